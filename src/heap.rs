@@ -0,0 +1,135 @@
+mod fibonacci_heap;
+pub use self::fibonacci_heap::*;
+
+use alloc::vec::Vec;
+
+/// A min-heap: `pop` and `peek` always act on the smallest element.
+pub trait Heap<T> {
+    /// Adds a value to the heap.
+    fn push(&mut self, value: T);
+
+    /// Removes the smallest element from the heap and returns it, or `None` if the heap is
+    /// empty.
+    fn pop(&mut self) -> Option<T>;
+
+    /// Returns a reference to the smallest element of the heap.
+    /// Returns `None` if the heap is empty.
+    fn peek(&self) -> Option<&T>;
+
+    /// Returns the length of the heap.
+    fn len(&self) -> usize;
+
+    /// Checks if the heap is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Sorts `items` in ascending order by pushing them all into a heap of type `H` and popping
+/// them back out.
+pub fn heap_sort<T, H>(items: impl IntoIterator<Item = T>) -> Vec<T>
+where
+    H: Heap<T> + Default,
+{
+    let mut heap = H::default();
+    for item in items {
+        heap.push(item);
+    }
+
+    let mut sorted = Vec::with_capacity(heap.len());
+    while let Some(item) = heap.pop() {
+        sorted.push(item);
+    }
+    sorted
+}
+
+/// Sorts `data` in ascending order in place: the slice is arranged into a max-heap, then the
+/// root is repeatedly swapped to the end of the shrinking heap. Uses O(1) extra space.
+pub fn heap_sort_slice<T: Ord>(data: &mut [T]) {
+    for start in (0..data.len() / 2).rev() {
+        sift_down(data, start);
+    }
+    for end in (1..data.len()).rev() {
+        data.swap(0, end);
+        sift_down(&mut data[..end], 0);
+    }
+}
+
+/// Moves `data[index]` down until neither child is larger, restoring the max-heap property.
+fn sift_down<T: Ord>(data: &mut [T], mut index: usize) {
+    loop {
+        let left = 2 * index + 1;
+        let right = left + 1;
+        let mut largest = index;
+        if left < data.len() && data[left] > data[largest] {
+            largest = left;
+        }
+        if right < data.len() && data[right] > data[largest] {
+            largest = right;
+        }
+        if largest == index {
+            return;
+        }
+        data.swap(index, largest);
+        index = largest;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn test_heap_sort_fibonacci() {
+        let items = [5, 3, 9, 1, 1, 8, 2, 7, 0, 6];
+        let mut expected = items.to_vec();
+        expected.sort();
+        let sorted = super::heap_sort::<_, super::FibonacciHeap<_>>(items);
+        assert_eq!(sorted, expected);
+    }
+
+    fn assert_heap_sort_slice(mut data: Vec<i64>) {
+        let mut expected = data.clone();
+        expected.sort();
+        super::heap_sort_slice(&mut data);
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn test_heap_sort_slice_random() {
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let data = (0..1000)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                (seed % 500) as i64 - 250
+            })
+            .collect();
+        assert_heap_sort_slice(data);
+    }
+
+    #[test]
+    fn test_heap_sort_slice_sorted_and_reversed() {
+        assert_heap_sort_slice((0..100).collect());
+        assert_heap_sort_slice((0..100).rev().collect());
+    }
+
+    #[test]
+    fn test_heap_sort_slice_all_equal() {
+        assert_heap_sort_slice(vec![7; 50]);
+    }
+
+    #[test]
+    fn test_heap_sort_slice_small() {
+        assert_heap_sort_slice(Vec::new());
+        assert_heap_sort_slice(vec![1]);
+        assert_heap_sort_slice(vec![2, 1]);
+    }
+
+    #[test]
+    fn test_heap_sort_empty() {
+        let sorted = super::heap_sort::<i32, super::FibonacciHeap<_>>(Vec::new());
+        assert!(sorted.is_empty());
+    }
+}
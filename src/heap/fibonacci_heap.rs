@@ -0,0 +1,332 @@
+use alloc::vec::Vec;
+
+use super::Heap;
+
+/// Identifies an element of a [`FibonacciHeap`] so its key can be decreased later.
+///
+/// A handle stops referring to anything once its element has been popped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Handle {
+    index: usize,
+    generation: usize,
+}
+
+#[derive(Debug, Clone)]
+struct FibNode<T> {
+    value: T,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    /// Index of this node in its parent's `children`, or in the root list.
+    position: usize,
+    marked: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Slot<T> {
+    generation: usize,
+    node: Option<FibNode<T>>,
+}
+
+/// A min-heap with O(1) amortized `insert` and `decrease_key` and O(log n) amortized `pop`.
+///
+/// Nodes live in an arena and refer to each other by index; freed slots are reused.
+#[derive(Debug, Clone)]
+pub struct FibonacciHeap<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<usize>,
+    roots: Vec<usize>,
+    min: Option<usize>,
+    len: usize,
+}
+
+impl<T> Default for FibonacciHeap<T> {
+    fn default() -> Self {
+        FibonacciHeap::new()
+    }
+}
+
+impl<T> FibonacciHeap<T> {
+    /// Creates an empty heap.
+    pub fn new() -> Self {
+        FibonacciHeap {
+            slots: Vec::new(),
+            free: Vec::new(),
+            roots: Vec::new(),
+            min: None,
+            len: 0,
+        }
+    }
+
+    /// Returns a reference to the smallest element of the heap.
+    /// Returns `None` if the heap is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.min.map(|index| &self.node(index).value)
+    }
+
+    /// Checks if the heap is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the length of the heap.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    fn node(&self, index: usize) -> &FibNode<T> {
+        self.slots[index].node.as_ref().expect("live node")
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut FibNode<T> {
+        self.slots[index].node.as_mut().expect("live node")
+    }
+
+    fn is_live(&self, handle: Handle) -> bool {
+        self.slots
+            .get(handle.index)
+            .is_some_and(|slot| slot.generation == handle.generation && slot.node.is_some())
+    }
+
+    /// Appends a node to the root list.
+    fn add_root(&mut self, index: usize) {
+        let position = self.roots.len();
+        let node = self.node_mut(index);
+        node.parent = None;
+        node.marked = false;
+        node.position = position;
+        self.roots.push(index);
+    }
+
+    /// Removes `index` from its parent's children and moves it to the root list.
+    fn cut(&mut self, index: usize, parent: usize) {
+        let position = self.node(index).position;
+        let children = &mut self.node_mut(parent).children;
+        children.swap_remove(position);
+        if let Some(&moved) = children.get(position) {
+            self.node_mut(moved).position = position;
+        }
+        self.add_root(index);
+    }
+}
+
+impl<T: Ord> FibonacciHeap<T> {
+    /// Adds a value to the heap and returns a handle to it.
+    pub fn insert(&mut self, value: T) -> Handle {
+        let node = FibNode {
+            value,
+            parent: None,
+            children: Vec::new(),
+            position: 0,
+            marked: false,
+        };
+        let index = match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index];
+                slot.generation += 1;
+                slot.node = Some(node);
+                index
+            }
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    node: Some(node),
+                });
+                self.slots.len() - 1
+            }
+        };
+
+        self.add_root(index);
+        self.update_min(index);
+        self.len += 1;
+
+        Handle {
+            index,
+            generation: self.slots[index].generation,
+        }
+    }
+
+    /// Removes the smallest element from the heap and returns it, or `None` if the heap is
+    /// empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let min = self.min?;
+        let node = self.slots[min].node.take().expect("live node");
+        self.free.push(min);
+        self.len -= 1;
+
+        self.roots.swap_remove(node.position);
+        for child in node.children {
+            self.add_root(child);
+        }
+        self.consolidate();
+
+        Some(node.value)
+    }
+
+    /// Lowers the value of the element behind `handle` to `value`.
+    ///
+    /// Returns `false`, leaving the heap unchanged, if the element has already been popped or
+    /// `value` is greater than its current value.
+    pub fn decrease_key(&mut self, handle: Handle, value: T) -> bool {
+        if !self.is_live(handle) || value > self.node(handle.index).value {
+            return false;
+        }
+
+        let index = handle.index;
+        self.node_mut(index).value = value;
+        if let Some(parent) = self.node(index).parent {
+            if self.node(index).value < self.node(parent).value {
+                self.cut(index, parent);
+                self.cascading_cut(parent);
+            }
+        }
+        self.update_min(index);
+        true
+    }
+
+    /// Walks up from `index`, cutting every already-marked ancestor and marking the first
+    /// unmarked one.
+    fn cascading_cut(&mut self, mut index: usize) {
+        while let Some(parent) = self.node(index).parent {
+            if !self.node(index).marked {
+                self.node_mut(index).marked = true;
+                return;
+            }
+            self.cut(index, parent);
+            index = parent;
+        }
+    }
+
+    fn update_min(&mut self, index: usize) {
+        let is_smaller = match self.min {
+            Some(min) => self.node(index).value < self.node(min).value,
+            None => true,
+        };
+        if is_smaller {
+            self.min = Some(index);
+        }
+    }
+
+    /// Links roots of equal degree until every root has a distinct degree, then rebuilds the
+    /// root list and finds the new minimum.
+    fn consolidate(&mut self) {
+        let mut by_degree: Vec<Option<usize>> = Vec::new();
+        for mut root in core::mem::take(&mut self.roots) {
+            let mut degree = self.node(root).children.len();
+            loop {
+                if degree >= by_degree.len() {
+                    by_degree.resize(degree + 1, None);
+                }
+                let Some(other) = by_degree[degree].take() else {
+                    break;
+                };
+                let (parent, child) = if self.node(other).value < self.node(root).value {
+                    (other, root)
+                } else {
+                    (root, other)
+                };
+                let position = self.node(parent).children.len();
+                self.node_mut(parent).children.push(child);
+                let node = self.node_mut(child);
+                node.parent = Some(parent);
+                node.position = position;
+                node.marked = false;
+
+                root = parent;
+                degree += 1;
+            }
+            by_degree[degree] = Some(root);
+        }
+
+        self.min = None;
+        for root in by_degree.into_iter().flatten() {
+            self.add_root(root);
+            self.update_min(root);
+        }
+    }
+}
+
+impl<T: Ord> Heap<T> for FibonacciHeap<T> {
+    fn push(&mut self, value: T) {
+        self.insert(value);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        FibonacciHeap::pop(self)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        FibonacciHeap::peek(self)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_fibonacci_heap_sorted_order() {
+        let mut heap = super::FibonacciHeap::new();
+        let values: Vec<u32> = (0..500).map(|i| (i * 37 + 5) % 101).collect();
+        for &value in &values {
+            heap.insert(value);
+        }
+        assert_eq!(heap.len(), values.len());
+        assert_eq!(heap.peek(), Some(&0));
+
+        let mut expected = values.clone();
+        expected.sort();
+        let mut popped = Vec::new();
+        while let Some(value) = heap.pop() {
+            popped.push(value);
+        }
+        assert_eq!(popped, expected);
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek(), None);
+    }
+
+    #[test]
+    fn test_fibonacci_heap_decrease_key() {
+        let mut heap = super::FibonacciHeap::new();
+        let handles: Vec<_> = (100..200).map(|value| heap.insert(value)).collect();
+        // Popping consolidates the roots into trees, so later decreases have parents to cut
+        // from.
+        assert_eq!(heap.pop(), Some(100));
+
+        let mut expected: Vec<i32> = (101..200).collect();
+        for (i, &handle) in handles.iter().enumerate().skip(1).step_by(3) {
+            let lowered = i as i32 - 100;
+            assert!(heap.decrease_key(handle, lowered));
+            expected[i - 1] = lowered;
+        }
+        expected.sort();
+        assert_eq!(heap.peek(), expected.first());
+
+        let mut popped = Vec::new();
+        while let Some(value) = heap.pop() {
+            popped.push(value);
+        }
+        assert_eq!(popped, expected);
+    }
+
+    #[test]
+    fn test_fibonacci_heap_decrease_key_rejected() {
+        let mut heap = super::FibonacciHeap::new();
+        let five = heap.insert(5);
+        let one = heap.insert(1);
+        assert!(!heap.decrease_key(five, 10));
+        assert_eq!(heap.pop(), Some(1));
+        assert!(!heap.decrease_key(one, 0));
+
+        // The popped slot is reused, but the old handle must not reach the new element.
+        let seven = heap.insert(7);
+        assert!(!heap.decrease_key(one, 0));
+        assert!(heap.decrease_key(seven, 2));
+        assert_eq!(heap.pop(), Some(2));
+        assert_eq!(heap.pop(), Some(5));
+        assert_eq!(heap.pop(), None);
+    }
+}
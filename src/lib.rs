@@ -2,4 +2,8 @@
 
 extern crate alloc;
 
-mod stack;
\ No newline at end of file
+pub mod heap;
+pub mod queue;
+pub mod stack;
+pub mod tree;
+pub mod trie;
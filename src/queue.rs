@@ -0,0 +1,65 @@
+mod array_deque;
+mod palindrome;
+mod round_robin;
+pub use self::array_deque::*;
+pub use self::palindrome::*;
+pub use self::round_robin::*;
+
+use alloc::vec::Vec;
+
+/// A first-in, first-out collection.
+pub trait Queue<T> {
+    /// Adds a value to the back of the queue.
+    fn enqueue(&mut self, item: T);
+
+    /// Removes the front element from the queue and returns it, or `None` if the queue is empty.
+    fn dequeue(&mut self) -> Option<T>;
+
+    /// Returns a reference to the front element of the queue.
+    /// Returns `None` if the queue is empty.
+    fn peek_front(&self) -> Option<&T>;
+
+    /// Returns the length of the queue.
+    fn len(&self) -> usize;
+
+    /// Checks if the queue is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Dequeues every element of `q` into a `Vec`, front first.
+pub fn drain_into_vec<T, Q: Queue<T>>(q: &mut Q) -> Vec<T> {
+    let mut items = Vec::with_capacity(q.len());
+    while let Some(item) = q.dequeue() {
+        items.push(item);
+    }
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Queue;
+    use alloc::vec;
+
+    fn exercise<Q: Queue<i32>>(mut queue: Q) {
+        assert!(queue.is_empty());
+        assert_eq!(queue.peek_front(), None);
+        queue.enqueue(1);
+        queue.enqueue(2);
+        queue.enqueue(3);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.peek_front(), Some(&1));
+        assert_eq!(queue.dequeue(), Some(1));
+        queue.enqueue(4);
+        assert_eq!(super::drain_into_vec(&mut queue), vec![2, 3, 4]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn test_queue_array_deque() {
+        exercise(super::ArrayDeque::new());
+        exercise(super::ArrayDeque::with_capacity(1));
+    }
+}
@@ -0,0 +1,262 @@
+use alloc::vec::Vec;
+
+use super::Queue;
+
+/// A double-ended queue backed by a growable ring buffer.
+#[derive(Debug, Clone)]
+pub struct ArrayDeque<T> {
+    buf: Vec<Option<T>>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> Default for ArrayDeque<T> {
+    fn default() -> Self {
+        ArrayDeque::new()
+    }
+}
+
+impl<T> ArrayDeque<T> {
+    /// Creates an empty deque.
+    pub fn new() -> Self {
+        ArrayDeque {
+            buf: Vec::new(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Creates an empty deque with room for `capacity` elements before it has to grow.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut buf = Vec::with_capacity(capacity);
+        buf.resize_with(capacity, || None);
+        ArrayDeque {
+            buf,
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements the deque can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Maps a logical index (0 is the front) to a slot in the ring buffer.
+    fn slot(&self, index: usize) -> usize {
+        (self.head + index) % self.buf.len()
+    }
+
+    /// Doubles the capacity, laying the elements out from the start of the new buffer.
+    fn grow(&mut self) {
+        let capacity = if self.buf.is_empty() {
+            4
+        } else {
+            self.buf.len() * 2
+        };
+        let mut buf = Vec::with_capacity(capacity);
+        for index in 0..self.len {
+            let slot = self.slot(index);
+            buf.push(self.buf[slot].take());
+        }
+        buf.resize_with(capacity, || None);
+
+        self.buf = buf;
+        self.head = 0;
+    }
+
+    /// Adds a value to the back of the deque.
+    pub fn push_back(&mut self, item: T) {
+        if self.len == self.buf.len() {
+            self.grow();
+        }
+        let slot = self.slot(self.len);
+        self.buf[slot] = Some(item);
+        self.len += 1;
+    }
+
+    /// Adds a value to the front of the deque.
+    pub fn push_front(&mut self, item: T) {
+        if self.len == self.buf.len() {
+            self.grow();
+        }
+        self.head = (self.head + self.buf.len() - 1) % self.buf.len();
+        self.buf[self.head] = Some(item);
+        self.len += 1;
+    }
+
+    /// Removes the front element and returns it, or `None` if the deque is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let item = self.buf[self.head].take();
+        self.head = (self.head + 1) % self.buf.len();
+        self.len -= 1;
+        item
+    }
+
+    /// Removes the back element and returns it, or `None` if the deque is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let slot = self.slot(self.len);
+        self.buf[slot].take()
+    }
+
+    /// Removes the oldest element and returns it, or `None` if the deque is empty.
+    ///
+    /// This is `pop_front` under its work-stealing name: the owner pushes and pops at the back,
+    /// while other workers steal the oldest work from the front.
+    pub fn steal(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    /// Returns a reference to the front element, or `None` if the deque is empty.
+    pub fn front(&self) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.buf[self.head].as_ref()
+    }
+
+    /// Returns a reference to the back element, or `None` if the deque is empty.
+    pub fn back(&self) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.buf[self.slot(self.len - 1)].as_ref()
+    }
+
+    /// Checks if the deque is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the length of the deque.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T> Queue<T> for ArrayDeque<T> {
+    fn enqueue(&mut self, item: T) {
+        self.push_back(item);
+    }
+
+    fn dequeue(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn peek_front(&self) -> Option<&T> {
+        self.front()
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T> core::fmt::Display for ArrayDeque<T>
+where
+    T: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "[")?;
+        for index in 0..self.len {
+            if let Some(item) = &self.buf[self.slot(index)] {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", item)?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    #[test]
+    fn test_array_deque_push_pop() {
+        let mut deque = super::ArrayDeque::new();
+        deque.push_back(2);
+        deque.push_back(3);
+        deque.push_front(1);
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.front(), Some(&1));
+        assert_eq!(deque.back(), Some(&3));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_front(), Some(2));
+        assert_eq!(deque.pop_front(), None);
+        assert_eq!(deque.pop_back(), None);
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_array_deque_work_stealing() {
+        let mut deque = super::ArrayDeque::new();
+        deque.push_back("task1");
+        deque.push_back("task2");
+        deque.push_back("task3");
+        assert_eq!(deque.steal(), Some("task1"));
+        assert_eq!(deque.pop_back(), Some("task3"));
+        assert_eq!(deque.steal(), Some("task2"));
+        assert_eq!(deque.steal(), None);
+        assert_eq!(deque.pop_back(), None);
+    }
+
+    #[test]
+    fn test_array_deque_wraparound() {
+        let mut deque = super::ArrayDeque::with_capacity(4);
+        for i in 0..4 {
+            deque.push_back(i);
+        }
+        assert_eq!(deque.pop_front(), Some(0));
+        assert_eq!(deque.pop_front(), Some(1));
+        deque.push_back(4);
+        deque.push_back(5);
+        assert_eq!(deque.capacity(), 4);
+        assert_eq!(deque.back(), Some(&5));
+        for i in 2..6 {
+            assert_eq!(deque.pop_front(), Some(i));
+        }
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_array_deque_grow_while_wrapped() {
+        let mut deque = super::ArrayDeque::with_capacity(4);
+        for i in 0..4 {
+            deque.push_back(i);
+        }
+        deque.pop_front();
+        deque.push_back(4);
+        deque.push_back(5);
+        assert!(deque.capacity() > 4);
+        assert_eq!(deque.len(), 5);
+        for i in 1..6 {
+            assert_eq!(deque.pop_front(), Some(i));
+        }
+    }
+
+    #[test]
+    fn test_array_deque_display() {
+        let mut deque = super::ArrayDeque::with_capacity(3);
+        deque.push_back(2);
+        deque.push_back(3);
+        deque.push_front(1);
+        assert_eq!(format!("{}", deque), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn test_array_deque_display_empty() {
+        let deque = super::ArrayDeque::<i32>::new();
+        assert_eq!(format!("{}", deque), "[]");
+    }
+}
@@ -0,0 +1,38 @@
+use super::ArrayDeque;
+
+/// Checks whether `input` reads the same forwards and backwards, ignoring case and any
+/// characters that are not alphanumeric.
+pub fn is_palindrome(input: &str) -> bool {
+    let mut deque = ArrayDeque::new();
+    for c in input.chars().filter(|c| c.is_alphanumeric()) {
+        for lower in c.to_lowercase() {
+            deque.push_back(lower);
+        }
+    }
+
+    while let (Some(front), Some(back)) = (deque.pop_front(), deque.pop_back()) {
+        if front != back {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_is_palindrome_sentence() {
+        assert!(super::is_palindrome("A man, a plan, a canal: Panama"));
+    }
+
+    #[test]
+    fn test_is_palindrome_false() {
+        assert!(!super::is_palindrome("hello"));
+    }
+
+    #[test]
+    fn test_is_palindrome_empty() {
+        assert!(super::is_palindrome(""));
+        assert!(super::is_palindrome("a"));
+    }
+}
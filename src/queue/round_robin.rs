@@ -0,0 +1,105 @@
+use alloc::vec::Vec;
+
+use super::ArrayDeque;
+
+/// A set of FIFO sub-queues, one per class, that are dequeued from in rotation.
+#[derive(Debug, Clone)]
+pub struct RoundRobinQueue<T> {
+    classes: Vec<ArrayDeque<T>>,
+    next: usize,
+    len: usize,
+}
+
+impl<T> RoundRobinQueue<T> {
+    /// Creates an empty queue with `classes` sub-queues.
+    pub fn new(classes: usize) -> Self {
+        let mut queues = Vec::with_capacity(classes);
+        queues.resize_with(classes, ArrayDeque::new);
+        RoundRobinQueue {
+            classes: queues,
+            next: 0,
+            len: 0,
+        }
+    }
+
+    /// Adds a value to the back of the sub-queue for `class`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `class` is not less than the number of classes.
+    pub fn enqueue(&mut self, class: usize, item: T) {
+        assert!(class < self.classes.len(), "class index out of range");
+        self.classes[class].push_back(item);
+        self.len += 1;
+    }
+
+    /// Removes and returns the front element of the next non-empty sub-queue in rotation, or
+    /// `None` if every sub-queue is empty.
+    pub fn dequeue(&mut self) -> Option<T> {
+        let count = self.classes.len();
+        for offset in 0..count {
+            let class = (self.next + offset) % count;
+            if let Some(item) = self.classes[class].pop_front() {
+                self.next = (class + 1) % count;
+                self.len -= 1;
+                return Some(item);
+            }
+        }
+        None
+    }
+
+    /// Checks if every sub-queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the total number of elements across all sub-queues.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_round_robin_rotates_classes() {
+        let mut queue = super::RoundRobinQueue::new(3);
+        queue.enqueue(0, "a1");
+        queue.enqueue(0, "a2");
+        queue.enqueue(0, "a3");
+        queue.enqueue(1, "b1");
+        queue.enqueue(2, "c1");
+        queue.enqueue(2, "c2");
+        assert_eq!(queue.len(), 6);
+
+        let mut order = Vec::new();
+        while let Some(item) = queue.dequeue() {
+            order.push(item);
+        }
+        assert_eq!(order, ["a1", "b1", "c1", "a2", "c2", "a3"]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_round_robin_skips_empty_classes() {
+        let mut queue = super::RoundRobinQueue::new(3);
+        queue.enqueue(2, 1);
+        queue.enqueue(2, 2);
+        assert_eq!(queue.dequeue(), Some(1));
+        queue.enqueue(0, 3);
+        queue.enqueue(1, 4);
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "class index out of range")]
+    fn test_round_robin_invalid_class() {
+        let mut queue = super::RoundRobinQueue::new(2);
+        queue.enqueue(2, 1);
+    }
+}
@@ -1,2 +1,6 @@
 mod linked_list_stack;
+mod small_stack;
+mod undo_stack;
 pub use self::linked_list_stack::{Node as LLSNoe, *};
+pub use self::small_stack::*;
+pub use self::undo_stack::*;
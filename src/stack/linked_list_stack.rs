@@ -1,4 +1,4 @@
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 
 #[derive(Debug, Clone)]
 pub struct Node<T> {
@@ -6,7 +6,7 @@ pub struct Node<T> {
     next: Option<Box<Node<T>>>,
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug)]
 pub struct LinkedListStack<T> {
     top: Option<Box<Node<T>>>,
     len: usize,
@@ -24,36 +24,101 @@ where
     }
 }
 
-impl<T> LinkedListStack<T>
-where
-    T: Clone + Default,
-{
+impl<T> Default for LinkedListStack<T> {
+    fn default() -> Self {
+        LinkedListStack { top: None, len: 0 }
+    }
+}
+
+/// Unlinks the nodes one at a time, since the default drop of a long chain of boxes recurses
+/// once per node and can overflow the call stack.
+impl<T> Drop for LinkedListStack<T> {
+    fn drop(&mut self) {
+        let mut next = self.top.take();
+        while let Some(mut node) = next {
+            next = node.next.take();
+        }
+    }
+}
+
+/// Copies the elements one at a time, since the derived clone of a long chain of boxes
+/// recurses once per node.
+impl<T: Clone> Clone for LinkedListStack<T> {
+    fn clone(&self) -> Self {
+        let items: Vec<&T> = self.iter().collect();
+        let mut stack = LinkedListStack::new();
+        for item in items.into_iter().rev() {
+            stack.push(item.clone());
+        }
+        stack
+    }
+}
+
+impl<T> LinkedListStack<T> {
     /// Creates an empty stack.
     pub fn new() -> Self {
         LinkedListStack { top: None, len: 0 }
     }
 
-    /// Pushes a value onto the stack.
-    pub fn push(&mut self, data: T) {
-        let mut node = Node::default();
+    /// Creates a stack from a slice, pushing its elements in order.
+    ///
+    /// The first slice element ends up at the bottom and the last at the top, so
+    /// `from_slice_preserving(&[1, 2, 3])` pops `3, 2, 1`.
+    pub fn from_slice_preserving(slice: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        let mut stack = LinkedListStack::new();
+        for item in slice {
+            stack.push(item.clone());
+        }
+        stack
+    }
 
-        node.data = data;
-        node.next = self.top.take();
+    /// Creates a stack from a slice, pushing its elements in reverse order.
+    ///
+    /// The first slice element ends up at the top and the last at the bottom, so
+    /// `from_reversed(&[1, 2, 3])` pops `1, 2, 3`.
+    pub fn from_reversed(slice: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        let mut stack = LinkedListStack::new();
+        for item in slice.iter().rev() {
+            stack.push(item.clone());
+        }
+        stack
+    }
 
-        self.top = Some(Box::new(node));
-        self.len += 1;
+    /// Creates a stack holding `n` clones of `value`.
+    pub fn repeat(value: T, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        let mut stack = LinkedListStack::new();
+        for _ in 0..n {
+            stack.push(value.clone());
+        }
+        stack
+    }
+
+    /// Pushes a value onto the stack.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the stack would overflow `usize`.
+    pub fn push(&mut self, data: T) {
+        self.push_node(Box::new(Node { data, next: None }));
     }
 
     /// Removes the top element from the stack and returns it, or `None` if the stack is empty.
     pub fn pop(&mut self) -> Option<T> {
         let top = self.top.take();
-        let result = top.map(|mut node| {
+        top.map(|mut node| {
             self.top = node.next.take();
             self.len -= 1;
             node.data
-        });
-
-        result
+        })
     }
 
     /// Checks if the stack is empty.
@@ -71,23 +136,322 @@ where
     pub fn peak(&self) -> Option<&T> {
         self.top.as_ref().map(|node| &node.data)
     }
+
+    /// Consumes the stack and returns it with its elements in the opposite order, so the
+    /// result pops in the order the original was pushed. The nodes are relinked, not cloned.
+    pub fn into_reversed(mut self) -> LinkedListStack<T> {
+        let mut reversed = LinkedListStack::new();
+        while let Some(node) = self.pop_node() {
+            reversed.push_node(node);
+        }
+        reversed
+    }
+
+    /// Removes consecutive equal elements, keeping the one nearest the top of each run.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        let mut removed = 0;
+        let mut current = self.top.as_mut();
+        while let Some(node) = current {
+            while let Some(mut next) = node.next.take() {
+                if next.data == node.data {
+                    node.next = next.next.take();
+                    removed += 1;
+                } else {
+                    node.next = Some(next);
+                    break;
+                }
+            }
+            current = node.next.as_mut();
+        }
+        self.len -= removed;
+    }
+
+    /// Returns an iterator over groups of up to `size` elements, from the top down. The last
+    /// group is shorter if the length is not a multiple of `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = Vec<&T>> + '_ {
+        assert!(size != 0, "chunk size must be non-zero");
+        let mut iter = self.iter();
+        core::iter::from_fn(move || {
+            let chunk: Vec<&T> = iter.by_ref().take(size).collect();
+            if chunk.is_empty() {
+                None
+            } else {
+                Some(chunk)
+            }
+        })
+    }
+
+    /// Returns references to the top element and the one below it.
+    /// Returns `None` if the stack has fewer than two elements.
+    pub fn peek_two(&self) -> Option<(&T, &T)> {
+        let top = self.top.as_ref()?;
+        let second = top.next.as_ref()?;
+        Some((&top.data, &second.data))
+    }
+
+    /// Returns a reference to the bottom element of the stack, walking the whole chain to
+    /// reach it, so this is O(n). Returns `None` if the stack is empty.
+    pub fn bottom(&self) -> Option<&T> {
+        self.iter().last()
+    }
+
+    /// Consumes the stack and splits it into the elements matching `f` and the rest, keeping
+    /// their relative order. The nodes are relinked, not cloned.
+    pub fn partition<F: Fn(&T) -> bool>(
+        mut self,
+        f: F,
+    ) -> (LinkedListStack<T>, LinkedListStack<T>) {
+        let mut matching = LinkedListStack::new();
+        let mut rest = LinkedListStack::new();
+        while let Some(node) = self.pop_node() {
+            if f(&node.data) {
+                matching.push_node(node);
+            } else {
+                rest.push_node(node);
+            }
+        }
+        (matching.into_reversed(), rest.into_reversed())
+    }
+
+    /// Replaces the top element with `value` and returns the old one. If the stack is empty,
+    /// `value` is pushed and `None` is returned.
+    pub fn replace_top(&mut self, value: T) -> Option<T> {
+        match self.top.as_mut() {
+            Some(node) => Some(core::mem::replace(&mut node.data, value)),
+            None => {
+                self.push(value);
+                None
+            }
+        }
+    }
+
+    /// Consumes both stacks and returns one that pops alternately from the top of `self` and
+    /// the top of `other`, starting with `self`. Once one runs out, the rest of the other
+    /// follows. The nodes are relinked, not cloned.
+    pub fn interleave(mut self, mut other: LinkedListStack<T>) -> LinkedListStack<T> {
+        let mut result = LinkedListStack::new();
+        loop {
+            match (self.pop_node(), other.pop_node()) {
+                (None, None) => break,
+                (first, second) => {
+                    for node in [first, second].into_iter().flatten() {
+                        result.push_node(node);
+                    }
+                }
+            }
+        }
+        result.into_reversed()
+    }
+
+    /// Returns a stack of running accumulations, walking from the top down. The result has
+    /// the same orientation: its top is `f(&init, top)`, and each element below folds in the
+    /// next element of `self`.
+    pub fn scan<B: Clone, F: FnMut(&B, &T) -> B>(&self, init: B, mut f: F) -> LinkedListStack<B> {
+        let mut result = LinkedListStack::new();
+        let mut acc = init;
+        for item in self.iter() {
+            acc = f(&acc, item);
+            result.push(acc.clone());
+        }
+        result.into_reversed()
+    }
+
+    /// Consumes both stacks and pairs up their elements from the top down. The result has as
+    /// many elements as the shorter stack; the rest of the longer one is dropped.
+    pub fn zip<U>(mut self, mut other: LinkedListStack<U>) -> LinkedListStack<(T, U)> {
+        let mut pairs = LinkedListStack::new();
+        while let (Some(a), Some(b)) = (self.pop(), other.pop()) {
+            pairs.push((a, b));
+        }
+        pairs.into_reversed()
+    }
+
+    /// Returns an iterator over `(depth, element)` pairs from the top down, where the top is
+    /// at depth 0.
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.iter().enumerate()
+    }
+
+    /// Folds the elements from the top down, stopping at the first `Err` returned by `f`.
+    pub fn try_fold<B, E, F: FnMut(B, &T) -> Result<B, E>>(
+        &self,
+        init: B,
+        mut f: F,
+    ) -> Result<B, E> {
+        let mut acc = init;
+        for item in self.iter() {
+            acc = f(acc, item)?;
+        }
+        Ok(acc)
+    }
+
+    /// Walking from the top, finds the first element matching `pred` and detaches it and
+    /// everything below it into a new stack, leaving the elements above it in `self`.
+    /// Returns `None`, leaving the stack unchanged, if no element matches.
+    pub fn split_when<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Option<LinkedListStack<T>> {
+        let mut depth = 0;
+        let mut link = &mut self.top;
+        while link.as_ref().is_some_and(|node| !pred(&node.data)) {
+            link = &mut link.as_mut().expect("checked above").next;
+            depth += 1;
+        }
+
+        let rest = link.take()?;
+        let split = LinkedListStack {
+            top: Some(rest),
+            len: self.len - depth,
+        };
+        self.len = depth;
+        Some(split)
+    }
+
+    /// Removes and returns the top element if it matches `pred`. Otherwise leaves the stack
+    /// unchanged and returns `None`.
+    pub fn pop_if<F: FnOnce(&T) -> bool>(&mut self, pred: F) -> Option<T> {
+        if pred(self.peak()?) {
+            self.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether both stacks hold the same elements with the same multiplicities,
+    /// ignoring order.
+    pub fn eq_ignore_order(&self, other: &LinkedListStack<T>) -> bool
+    where
+        T: Ord,
+    {
+        if self.len != other.len {
+            return false;
+        }
+        let mut mine: Vec<&T> = self.iter().collect();
+        let mut theirs: Vec<&T> = other.iter().collect();
+        mine.sort_unstable();
+        theirs.sort_unstable();
+        mine == theirs
+    }
+
+    /// Pops elements for as long as the top matches `pred` and returns them in pop order. The
+    /// first non-matching element stays on the stack.
+    pub fn drain_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+        let mut drained = Vec::new();
+        while let Some(item) = self.pop_if(&mut pred) {
+            drained.push(item);
+        }
+        drained
+    }
+
+    /// Consumes the stack and collapses each run of equal adjacent elements into a
+    /// `(value, count)` pair, keeping the top-down order.
+    pub fn run_length(mut self) -> LinkedListStack<(T, usize)>
+    where
+        T: PartialEq,
+    {
+        let mut runs: LinkedListStack<(T, usize)> = LinkedListStack::new();
+        while let Some(item) = self.pop() {
+            match runs.top.as_mut() {
+                Some(run) if run.data.0 == item => run.data.1 += 1,
+                _ => runs.push((item, 1)),
+            }
+        }
+        runs.into_reversed()
+    }
+
+    /// Returns an iterator over the elements of the stack, from the top down.
+    fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.top.as_deref(),
+        }
+    }
+
+    /// Detaches the top node from the stack without unboxing it.
+    fn pop_node(&mut self) -> Option<Box<Node<T>>> {
+        self.top.take().map(|mut node| {
+            self.top = node.next.take();
+            self.len -= 1;
+            node
+        })
+    }
+
+    /// Links an existing node onto the top of the stack.
+    fn push_node(&mut self, mut node: Box<Node<T>>) {
+        let len = self
+            .len
+            .checked_add(1)
+            .expect("stack length overflowed usize");
+        node.next = self.top.take();
+        self.top = Some(node);
+        self.len = len;
+    }
+
+    /// Walks the chain and asserts that the number of nodes matches `len`.
+    #[cfg(test)]
+    pub(crate) fn assert_valid(&self) {
+        assert_eq!(
+            self.iter().count(),
+            self.len,
+            "node count does not match len"
+        );
+    }
+}
+
+/// An iterator over references to the elements of a stack, from the top down.
+struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.data
+        })
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedListStack<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedListStack<T> {}
+
+/// Stacks are compared lexicographically from the top down, like slices.
+impl<T: PartialOrd> PartialOrd for LinkedListStack<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for LinkedListStack<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.iter().cmp(other.iter())
+    }
 }
 
 impl<T> core::fmt::Display for LinkedListStack<T>
 where
-    T: Clone + Default + core::fmt::Display,
+    T: core::fmt::Display,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         let mut first = true;
-        let mut iter = self.top.clone();
-        while let Some(node) = iter.take() {
+        for item in self.iter() {
             if first {
-                write!(f, "{}", node.data)?;
+                write!(f, "{}", item)?;
                 first = false;
             } else {
-                write!(f, " -> {}", node.data)?;
+                write!(f, " -> {}", item)?;
             }
-            iter = node.next.clone();
         }
         Ok(())
     }
@@ -95,7 +459,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use alloc::{format, string::String};
+    use alloc::{format, string::String, vec, vec::Vec};
 
     #[test]
     fn test_stack_push() {
@@ -126,11 +490,11 @@ mod tests {
     #[test]
     fn test_stack_is_empty() {
         let mut stack = super::LinkedListStack::new();
-        assert_eq!(stack.is_empty(), true);
+        assert!(stack.is_empty());
         stack.push(1);
-        assert_eq!(stack.is_empty(), false);
+        assert!(!stack.is_empty());
         stack.pop();
-        assert_eq!(stack.is_empty(), true);
+        assert!(stack.is_empty());
     }
 
     #[test]
@@ -183,4 +547,408 @@ mod tests {
         stack.pop();
         assert_eq!(stack.peak(), None);
     }
+
+    #[test]
+    fn test_stack_from_slice_preserving() {
+        let mut stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 3]);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peak(), Some(&3));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn test_stack_from_reversed() {
+        let mut stack = super::LinkedListStack::from_reversed(&[1, 2, 3]);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peak(), Some(&1));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn test_stack_ord() {
+        let one_two = super::LinkedListStack::from_reversed(&[1, 2]);
+        let one_three = super::LinkedListStack::from_reversed(&[1, 3]);
+        let one = super::LinkedListStack::from_reversed(&[1]);
+        assert!(one_two < one_three);
+        assert!(one < one_two);
+        assert_eq!(one_two.cmp(&one_three), core::cmp::Ordering::Less);
+        assert_eq!(one_three.cmp(&one), core::cmp::Ordering::Greater);
+    }
+
+    #[test]
+    fn test_stack_eq() {
+        let a = super::LinkedListStack::from_reversed(&[1, 2, 3]);
+        let b = super::LinkedListStack::from_reversed(&[1, 2, 3]);
+        assert_eq!(a, b);
+        assert_eq!(a.cmp(&b), core::cmp::Ordering::Equal);
+        assert_ne!(a, super::LinkedListStack::from_reversed(&[1, 2]));
+    }
+
+    #[test]
+    fn test_stack_clone() {
+        let stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 3]);
+        let mut copy = stack.clone();
+        copy.assert_valid();
+        assert_eq!(copy, stack);
+        assert_eq!(copy.pop(), Some(3));
+        assert_eq!(stack.len(), 3);
+    }
+
+    #[test]
+    fn test_stack_clone_deep() {
+        let stack = super::LinkedListStack::repeat(1u8, 1_000_000);
+        let copy = stack.clone();
+        assert_eq!(copy.len(), 1_000_000);
+    }
+
+    #[test]
+    fn test_stack_into_reversed() {
+        let mut stack = super::LinkedListStack::new();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        let mut reversed = stack.into_reversed();
+        reversed.assert_valid();
+        assert_eq!(reversed.len(), 3);
+        assert_eq!(reversed.pop(), Some(1));
+        assert_eq!(reversed.pop(), Some(2));
+        assert_eq!(reversed.pop(), Some(3));
+        assert_eq!(reversed.pop(), None);
+    }
+
+    #[test]
+    fn test_stack_default_without_t_default() {
+        struct NoDefault;
+
+        let stack = super::LinkedListStack::<NoDefault>::default();
+        assert!(stack.top.is_none());
+        assert_eq!(stack.len, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "node count does not match len")]
+    fn test_stack_assert_valid_detects_len_desync() {
+        let mut stack = super::LinkedListStack::new();
+        stack.push(1);
+        stack.len = 2;
+        stack.assert_valid();
+    }
+
+    #[test]
+    fn test_stack_dedup() {
+        let mut stack = super::LinkedListStack::from_slice_preserving(&[1, 1, 2, 3, 3]);
+        stack.dedup();
+        stack.assert_valid();
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn test_stack_chunks() {
+        let stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 3, 4, 5]);
+        let chunks: Vec<Vec<&i32>> = stack.chunks(2).collect();
+        assert_eq!(chunks, vec![vec![&5, &4], vec![&3, &2], vec![&1]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_stack_chunks_zero() {
+        let stack = super::LinkedListStack::from_slice_preserving(&[1]);
+        let _ = stack.chunks(0);
+    }
+
+    #[test]
+    fn test_stack_peek_two() {
+        let mut stack = super::LinkedListStack::new();
+        assert_eq!(stack.peek_two(), None);
+        stack.push(1);
+        assert_eq!(stack.peek_two(), None);
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(stack.peek_two(), Some((&3, &2)));
+        assert_eq!(stack.len(), 3);
+    }
+
+    #[test]
+    fn test_stack_bottom() {
+        let stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 3]);
+        assert_eq!(stack.peak(), Some(&3));
+        assert_eq!(stack.bottom(), Some(&1));
+    }
+
+    #[test]
+    fn test_stack_bottom_empty() {
+        let stack = super::LinkedListStack::<i32>::new();
+        assert_eq!(stack.bottom(), None);
+    }
+
+    #[test]
+    fn test_stack_partition() {
+        let stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 3, 4]);
+        let (mut even, mut odd) = stack.partition(|x| x % 2 == 0);
+        even.assert_valid();
+        odd.assert_valid();
+        assert_eq!(even.len(), 2);
+        assert_eq!(even.pop(), Some(4));
+        assert_eq!(even.pop(), Some(2));
+        assert_eq!(even.pop(), None);
+        assert_eq!(odd.len(), 2);
+        assert_eq!(odd.pop(), Some(3));
+        assert_eq!(odd.pop(), Some(1));
+        assert_eq!(odd.pop(), None);
+    }
+
+    #[test]
+    fn test_stack_replace_top() {
+        let mut stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 3]);
+        assert_eq!(stack.replace_top(9), Some(3));
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some(9));
+        assert_eq!(stack.pop(), Some(2));
+    }
+
+    #[test]
+    fn test_stack_replace_top_empty() {
+        let mut stack = super::LinkedListStack::new();
+        assert_eq!(stack.replace_top(1), None);
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack.peak(), Some(&1));
+    }
+
+    #[test]
+    fn test_stack_interleave() {
+        let a = super::LinkedListStack::from_slice_preserving(&[1, 2, 3]);
+        let b = super::LinkedListStack::from_slice_preserving(&[4, 5]);
+        let mut stack = a.interleave(b);
+        stack.assert_valid();
+        assert_eq!(stack.len(), 5);
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(5));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(4));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "stack length overflowed usize")]
+    fn test_stack_push_len_overflow() {
+        let mut stack = super::LinkedListStack::new();
+        stack.len = usize::MAX;
+        stack.push(1);
+    }
+
+    #[test]
+    fn test_stack_scan() {
+        let stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 3]);
+        let mut sums = stack.scan(0, |acc, x| acc + x);
+        assert_eq!(sums.len(), 3);
+        assert_eq!(sums.pop(), Some(3));
+        assert_eq!(sums.pop(), Some(5));
+        assert_eq!(sums.pop(), Some(6));
+        assert_eq!(sums.pop(), None);
+        assert_eq!(stack.len(), 3);
+    }
+
+    #[test]
+    fn test_stack_zip() {
+        let numbers = super::LinkedListStack::from_slice_preserving(&[1, 2, 3]);
+        let letters = super::LinkedListStack::from_slice_preserving(&['a', 'b']);
+        let mut pairs = numbers.zip(letters);
+        pairs.assert_valid();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs.pop(), Some((3, 'b')));
+        assert_eq!(pairs.pop(), Some((2, 'a')));
+        assert_eq!(pairs.pop(), None);
+    }
+
+    #[test]
+    fn test_stack_zip_drops_long_remainder() {
+        let long = super::LinkedListStack::repeat(1u8, 1_000_000);
+        let short = super::LinkedListStack::from_slice_preserving(&['a']);
+        let mut pairs = long.zip(short);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs.pop(), Some((1, 'a')));
+    }
+
+    #[test]
+    fn test_stack_repeat() {
+        let mut stack = super::LinkedListStack::repeat(7, 3);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some(7));
+        assert_eq!(stack.pop(), Some(7));
+        assert_eq!(stack.pop(), Some(7));
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn test_stack_repeat_zero() {
+        let stack = super::LinkedListStack::repeat(7, 0);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_stack_iter_indexed() {
+        let stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 3]);
+        let pairs: Vec<(usize, &i32)> = stack.iter_indexed().collect();
+        assert_eq!(pairs, vec![(0, &3), (1, &2), (2, &1)]);
+        assert_eq!(pairs.first(), Some(&(0, stack.peak().unwrap())));
+        assert_eq!(
+            pairs.last(),
+            Some(&(stack.len() - 1, stack.bottom().unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_stack_try_fold() {
+        let stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 3]);
+        let sum: Result<i32, i32> = stack.try_fold(0, |acc, &x| Ok(acc + x));
+        assert_eq!(sum, Ok(6));
+    }
+
+    #[test]
+    fn test_stack_try_fold_stops_early() {
+        // Top down: 4, 3, -2, 1.
+        let stack = super::LinkedListStack::from_slice_preserving(&[1, -2, 3, 4]);
+        let mut visited = 0;
+        let sum = stack.try_fold(0, |acc, &x| {
+            visited += 1;
+            if x < 0 {
+                Err(x)
+            } else {
+                Ok(acc + x)
+            }
+        });
+        assert_eq!(sum, Err(-2));
+        assert_eq!(visited, 3);
+    }
+
+    #[test]
+    fn test_stack_split_when() {
+        // Top down: 4, 3, 2, 1; splitting at the first odd element.
+        let mut stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 3, 4]);
+        let mut rest = stack.split_when(|x| x % 2 == 1).unwrap();
+        stack.assert_valid();
+        rest.assert_valid();
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack.pop(), Some(4));
+        assert_eq!(stack.pop(), None);
+        assert_eq!(rest.len(), 3);
+        assert_eq!(rest.pop(), Some(3));
+        assert_eq!(rest.pop(), Some(2));
+        assert_eq!(rest.pop(), Some(1));
+        assert_eq!(rest.pop(), None);
+    }
+
+    #[test]
+    fn test_stack_split_when_first_even() {
+        let mut stack = super::LinkedListStack::from_reversed(&[1, 2, 3, 4]);
+        let rest = stack.split_when(|x| x % 2 == 0).unwrap();
+        stack.assert_valid();
+        rest.assert_valid();
+        assert_eq!(stack.peak(), Some(&1));
+        assert_eq!(stack.len(), 1);
+        assert_eq!(rest.peak(), Some(&2));
+        assert_eq!(rest.len(), 3);
+    }
+
+    #[test]
+    fn test_stack_split_when_at_top() {
+        // Top down: 4, 3, 2, 1; the top itself is the first even element.
+        let mut stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 3, 4]);
+        let mut rest = stack.split_when(|x| x % 2 == 0).unwrap();
+        assert!(stack.is_empty());
+        stack.assert_valid();
+        rest.assert_valid();
+        assert_eq!(rest.len(), 4);
+        assert_eq!(rest.pop(), Some(4));
+        assert_eq!(rest.pop(), Some(3));
+        assert_eq!(rest.pop(), Some(2));
+        assert_eq!(rest.pop(), Some(1));
+    }
+
+    #[test]
+    fn test_stack_split_when_no_match() {
+        let mut stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 3, 4]);
+        assert!(stack.split_when(|x| *x > 10).is_none());
+        stack.assert_valid();
+        assert_eq!(stack.len(), 4);
+        assert_eq!(stack.peak(), Some(&4));
+    }
+
+    #[test]
+    fn test_stack_pop_if() {
+        let mut stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 3]);
+        assert_eq!(stack.pop_if(|x| *x > 5), None);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop_if(|x| *x > 2), Some(3));
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.peak(), Some(&2));
+    }
+
+    #[test]
+    fn test_stack_pop_if_empty() {
+        let mut stack = super::LinkedListStack::<i32>::new();
+        assert_eq!(stack.pop_if(|_| true), None);
+    }
+
+    #[test]
+    fn test_stack_eq_ignore_order() {
+        let a = super::LinkedListStack::from_slice_preserving(&[1, 2, 3]);
+        let b = super::LinkedListStack::from_slice_preserving(&[3, 1, 2]);
+        assert!(a.eq_ignore_order(&b));
+        assert!(b.eq_ignore_order(&a));
+    }
+
+    #[test]
+    fn test_stack_eq_ignore_order_duplicates() {
+        let a = super::LinkedListStack::from_slice_preserving(&[1, 1, 2]);
+        let b = super::LinkedListStack::from_slice_preserving(&[1, 2, 2]);
+        assert!(!a.eq_ignore_order(&b));
+        assert!(!a.eq_ignore_order(&super::LinkedListStack::from_slice_preserving(&[1, 2])));
+    }
+
+    #[test]
+    fn test_stack_drain_while() {
+        let mut stack = super::LinkedListStack::from_slice_preserving(&[1, 2, 5, 6]);
+        assert_eq!(stack.drain_while(|x| *x > 4), vec![6, 5]);
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn test_stack_drain_while_all() {
+        let mut stack = super::LinkedListStack::from_slice_preserving(&[1, 2]);
+        assert_eq!(stack.drain_while(|_| true), vec![2, 1]);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_stack_run_length() {
+        let stack = super::LinkedListStack::from_reversed(&[3, 3, 3, 2, 1, 1]);
+        let mut runs = stack.run_length();
+        runs.assert_valid();
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs.pop(), Some((3, 3)));
+        assert_eq!(runs.pop(), Some((2, 1)));
+        assert_eq!(runs.pop(), Some((1, 2)));
+        assert_eq!(runs.pop(), None);
+    }
+
+    #[test]
+    fn test_stack_run_length_empty() {
+        let stack = super::LinkedListStack::<i32>::new();
+        assert!(stack.run_length().is_empty());
+    }
 }
@@ -0,0 +1,137 @@
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone)]
+enum Storage<T, const N: usize> {
+    Inline { items: [Option<T>; N], len: usize },
+    Spilled(Vec<T>),
+}
+
+/// A stack that keeps up to `N` elements inline and only moves them to the heap once it
+/// overflows.
+#[derive(Debug, Clone)]
+pub struct SmallStack<T, const N: usize> {
+    storage: Storage<T, N>,
+}
+
+impl<T, const N: usize> Default for SmallStack<T, N> {
+    fn default() -> Self {
+        SmallStack::new()
+    }
+}
+
+impl<T, const N: usize> SmallStack<T, N> {
+    /// Creates an empty stack with inline storage.
+    pub fn new() -> Self {
+        SmallStack {
+            storage: Storage::Inline {
+                items: core::array::from_fn(|_| None),
+                len: 0,
+            },
+        }
+    }
+
+    /// Pushes a value onto the stack, spilling to the heap if the inline storage is full.
+    pub fn push(&mut self, data: T) {
+        match &mut self.storage {
+            Storage::Inline { items, len } if *len < N => {
+                items[*len] = Some(data);
+                *len += 1;
+            }
+            Storage::Inline { items, .. } => {
+                let mut heap = Vec::with_capacity(N * 2 + 1);
+                heap.extend(items.iter_mut().filter_map(Option::take));
+                heap.push(data);
+                self.storage = Storage::Spilled(heap);
+            }
+            Storage::Spilled(heap) => heap.push(data),
+        }
+    }
+
+    /// Removes the top element from the stack and returns it, or `None` if the stack is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        match &mut self.storage {
+            Storage::Inline { items, len } => {
+                if *len == 0 {
+                    return None;
+                }
+                *len -= 1;
+                items[*len].take()
+            }
+            Storage::Spilled(heap) => heap.pop(),
+        }
+    }
+
+    /// Returns a reference to the top element of the stack.
+    /// Returns `None` if the stack is empty.
+    pub fn peek(&self) -> Option<&T> {
+        match &self.storage {
+            Storage::Inline { items, len } => {
+                len.checked_sub(1).and_then(|top| items[top].as_ref())
+            }
+            Storage::Spilled(heap) => heap.last(),
+        }
+    }
+
+    /// Checks if the stack is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the length of the stack.
+    pub fn len(&self) -> usize {
+        match &self.storage {
+            Storage::Inline { len, .. } => *len,
+            Storage::Spilled(heap) => heap.len(),
+        }
+    }
+
+    /// Returns `true` if the elements have been moved to the heap.
+    pub fn spilled(&self) -> bool {
+        matches!(self.storage, Storage::Spilled(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_small_stack_inline() {
+        let mut stack = super::SmallStack::<i32, 3>::new();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert!(!stack.spilled());
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Some(&3));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        assert!(!stack.spilled());
+    }
+
+    #[test]
+    fn test_small_stack_spill() {
+        let mut stack = super::SmallStack::<i32, 2>::new();
+        stack.push(1);
+        stack.push(2);
+        assert!(!stack.spilled());
+        stack.push(3);
+        assert!(stack.spilled());
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Some(&3));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_small_stack_zero_capacity() {
+        let mut stack = super::SmallStack::<i32, 0>::new();
+        assert_eq!(stack.peek(), None);
+        stack.push(1);
+        assert!(stack.spilled());
+        assert_eq!(stack.pop(), Some(1));
+    }
+}
@@ -0,0 +1,104 @@
+use super::LinkedListStack;
+
+/// An undo/redo history built on two stacks.
+#[derive(Debug, Clone)]
+pub struct UndoStack<T> {
+    undo: LinkedListStack<T>,
+    redo: LinkedListStack<T>,
+}
+
+impl<T> Default for UndoStack<T> {
+    fn default() -> Self {
+        UndoStack::new()
+    }
+}
+
+impl<T> UndoStack<T> {
+    /// Creates an empty history.
+    pub fn new() -> Self {
+        UndoStack {
+            undo: LinkedListStack::new(),
+            redo: LinkedListStack::new(),
+        }
+    }
+
+    /// Records a new state. Anything that could have been redone is discarded.
+    pub fn do_action(&mut self, state: T) {
+        self.undo.push(state);
+        self.redo = LinkedListStack::new();
+    }
+
+    /// Moves the most recent state onto the redo stack and returns it, or `None` if there is
+    /// nothing to undo.
+    pub fn undo(&mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        let state = self.undo.pop()?;
+        self.redo.push(state.clone());
+        Some(state)
+    }
+
+    /// Moves the most recently undone state back onto the undo stack and returns it, or
+    /// `None` if there is nothing to redo.
+    pub fn redo(&mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        let state = self.redo.pop()?;
+        self.undo.push(state.clone());
+        Some(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_undo_stack_undo_redo() {
+        let mut history = super::UndoStack::new();
+        history.do_action("a");
+        history.do_action("b");
+        history.do_action("c");
+        assert_eq!(history.undo(), Some("c"));
+        assert_eq!(history.undo(), Some("b"));
+        assert_eq!(history.redo(), Some("b"));
+        assert_eq!(history.undo(), Some("b"));
+        assert_eq!(history.undo(), Some("a"));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), Some("a"));
+    }
+
+    #[test]
+    fn test_undo_stack_action_clears_redo() {
+        let mut history = super::UndoStack::new();
+        history.do_action(1);
+        history.do_action(2);
+        history.do_action(3);
+        assert_eq!(history.undo(), Some(3));
+        assert_eq!(history.undo(), Some(2));
+        history.do_action(4);
+        assert_eq!(history.redo(), None);
+        assert_eq!(history.undo(), Some(4));
+        assert_eq!(history.undo(), Some(1));
+        assert_eq!(history.undo(), None);
+    }
+
+    #[test]
+    fn test_undo_stack_empty() {
+        let mut history = super::UndoStack::<i32>::new();
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), None);
+    }
+
+    #[test]
+    fn test_undo_stack_action_clears_deep_redo() {
+        let mut history = super::UndoStack::new();
+        for i in 0..1_000_000 {
+            history.do_action(i);
+        }
+        while history.undo().is_some() {}
+        history.do_action(-1);
+        assert_eq!(history.redo(), None);
+        assert_eq!(history.undo(), Some(-1));
+    }
+}
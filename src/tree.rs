@@ -0,0 +1,2 @@
+mod traversal;
+pub use self::traversal::*;
@@ -0,0 +1,250 @@
+//! Iterative binary tree traversals driven by a [`LinkedListStack`], so deep or
+//! degenerate trees do not exhaust the call stack.
+//!
+//! Each function takes the root, a `children` closure returning a node's left and right
+//! children, and a `visit` closure called once per node in traversal order.
+
+use crate::stack::LinkedListStack;
+
+/// Visits every node before its children: node, left subtree, right subtree.
+pub fn preorder<'a, N, C, V>(root: Option<&'a N>, children: C, mut visit: V)
+where
+    C: Fn(&'a N) -> (Option<&'a N>, Option<&'a N>),
+    V: FnMut(&'a N),
+{
+    let mut stack = LinkedListStack::new();
+    if let Some(root) = root {
+        stack.push(root);
+    }
+
+    while let Some(node) = stack.pop() {
+        visit(node);
+        let (left, right) = children(node);
+        if let Some(right) = right {
+            stack.push(right);
+        }
+        if let Some(left) = left {
+            stack.push(left);
+        }
+    }
+}
+
+/// Visits every node between its subtrees: left subtree, node, right subtree.
+pub fn inorder<'a, N, C, V>(root: Option<&'a N>, children: C, mut visit: V)
+where
+    C: Fn(&'a N) -> (Option<&'a N>, Option<&'a N>),
+    V: FnMut(&'a N),
+{
+    let mut stack = LinkedListStack::new();
+    let mut current = root;
+
+    loop {
+        while let Some(node) = current {
+            stack.push(node);
+            current = children(node).0;
+        }
+        match stack.pop() {
+            Some(node) => {
+                visit(node);
+                current = children(node).1;
+            }
+            None => break,
+        }
+    }
+}
+
+/// Visits every node after its children: left subtree, right subtree, node.
+pub fn postorder<'a, N, C, V>(root: Option<&'a N>, children: C, mut visit: V)
+where
+    C: Fn(&'a N) -> (Option<&'a N>, Option<&'a N>),
+    V: FnMut(&'a N),
+{
+    let mut pending = LinkedListStack::new();
+    let mut output = LinkedListStack::new();
+    if let Some(root) = root {
+        pending.push(root);
+    }
+
+    // Collect nodes as node, right, left, then unwind them in reverse.
+    while let Some(node) = pending.pop() {
+        output.push(node);
+        let (left, right) = children(node);
+        if let Some(left) = left {
+            pending.push(left);
+        }
+        if let Some(right) = right {
+            pending.push(right);
+        }
+    }
+
+    while let Some(node) = output.pop() {
+        visit(node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    /// A tree node stored in an arena, so that building and dropping a deep tree does not
+    /// recurse.
+    struct Node {
+        value: u32,
+        left: Option<usize>,
+        right: Option<usize>,
+    }
+
+    fn children<'a>(
+        nodes: &'a [Node],
+    ) -> impl Fn(&'a Node) -> (Option<&'a Node>, Option<&'a Node>) {
+        move |node| (node.left.map(|i| &nodes[i]), node.right.map(|i| &nodes[i]))
+    }
+
+    /// Builds an unbalanced binary search tree by inserting `values` in order.
+    fn build(values: &[u32]) -> Vec<Node> {
+        let mut nodes: Vec<Node> = Vec::new();
+        for &value in values {
+            let index = nodes.len();
+            nodes.push(Node {
+                value,
+                left: None,
+                right: None,
+            });
+            if index == 0 {
+                continue;
+            }
+            let mut current = 0;
+            loop {
+                let slot = if value < nodes[current].value {
+                    &mut nodes[current].left
+                } else {
+                    &mut nodes[current].right
+                };
+                match *slot {
+                    Some(next) => current = next,
+                    None => {
+                        *slot = Some(index);
+                        break;
+                    }
+                }
+            }
+        }
+        nodes
+    }
+
+    fn preorder_recursive(nodes: &[Node], index: Option<usize>, out: &mut Vec<u32>) {
+        if let Some(i) = index {
+            out.push(nodes[i].value);
+            preorder_recursive(nodes, nodes[i].left, out);
+            preorder_recursive(nodes, nodes[i].right, out);
+        }
+    }
+
+    fn inorder_recursive(nodes: &[Node], index: Option<usize>, out: &mut Vec<u32>) {
+        if let Some(i) = index {
+            inorder_recursive(nodes, nodes[i].left, out);
+            out.push(nodes[i].value);
+            inorder_recursive(nodes, nodes[i].right, out);
+        }
+    }
+
+    fn postorder_recursive(nodes: &[Node], index: Option<usize>, out: &mut Vec<u32>) {
+        if let Some(i) = index {
+            postorder_recursive(nodes, nodes[i].left, out);
+            postorder_recursive(nodes, nodes[i].right, out);
+            out.push(nodes[i].value);
+        }
+    }
+
+    fn medium_tree() -> Vec<Node> {
+        // A fixed pseudo-random permutation of 0..200.
+        let values: Vec<u32> = (0..200).map(|i| (i * 73 + 11) % 200).collect();
+        build(&values)
+    }
+
+    #[test]
+    fn test_preorder_matches_recursive() {
+        let nodes = medium_tree();
+        let mut expected = Vec::new();
+        preorder_recursive(&nodes, Some(0), &mut expected);
+
+        let mut actual = Vec::new();
+        super::preorder(nodes.first(), children(&nodes), |node| {
+            actual.push(node.value)
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_inorder_matches_recursive() {
+        let nodes = medium_tree();
+        let mut expected = Vec::new();
+        inorder_recursive(&nodes, Some(0), &mut expected);
+
+        let mut actual = Vec::new();
+        super::inorder(nodes.first(), children(&nodes), |node| {
+            actual.push(node.value)
+        });
+        assert_eq!(actual, expected);
+        assert_eq!(actual, (0..200).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_postorder_matches_recursive() {
+        let nodes = medium_tree();
+        let mut expected = Vec::new();
+        postorder_recursive(&nodes, Some(0), &mut expected);
+
+        let mut actual = Vec::new();
+        super::postorder(nodes.first(), children(&nodes), |node| {
+            actual.push(node.value)
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let nodes: Vec<Node> = Vec::new();
+        let mut count = 0;
+        super::preorder(nodes.first(), children(&nodes), |_| count += 1);
+        super::inorder(nodes.first(), children(&nodes), |_| count += 1);
+        super::postorder(nodes.first(), children(&nodes), |_| count += 1);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_degenerate_tree() {
+        // Ascending inserts produce a right-leaning chain deep enough that the recursive
+        // versions above would overflow the stack.
+        const DEPTH: u32 = 200_000;
+        let mut nodes: Vec<Node> = (0..DEPTH)
+            .map(|value| Node {
+                value,
+                left: None,
+                right: None,
+            })
+            .collect();
+        for i in 1..nodes.len() {
+            nodes[i - 1].right = Some(i);
+        }
+        let ascending: Vec<u32> = (0..DEPTH).collect();
+
+        let mut actual = Vec::new();
+        super::preorder(nodes.first(), children(&nodes), |node| {
+            actual.push(node.value)
+        });
+        assert_eq!(actual, ascending);
+
+        actual.clear();
+        super::inorder(nodes.first(), children(&nodes), |node| {
+            actual.push(node.value)
+        });
+        assert_eq!(actual, ascending);
+
+        actual.clear();
+        super::postorder(nodes.first(), children(&nodes), |node| {
+            actual.push(node.value)
+        });
+        assert!(actual.iter().eq(ascending.iter().rev()));
+    }
+}
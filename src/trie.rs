@@ -0,0 +1,2 @@
+mod trie_map;
+pub use self::trie_map::*;
@@ -0,0 +1,324 @@
+use alloc::{
+    collections::{btree_map, BTreeMap},
+    vec::Vec,
+};
+
+use crate::stack::LinkedListStack;
+
+#[derive(Debug)]
+struct TrieNode<V> {
+    value: Option<V>,
+    children: BTreeMap<char, TrieNode<V>>,
+}
+
+impl<V> TrieNode<V> {
+    fn new() -> Self {
+        TrieNode {
+            value: None,
+            children: BTreeMap::new(),
+        }
+    }
+}
+
+/// A map from string keys to values, stored as a prefix tree.
+///
+/// Every node is one character deep, so operations that walk or tear down the tree do so with
+/// an explicit stack rather than recursion, which long keys would overflow.
+#[derive(Debug)]
+pub struct TrieMap<V> {
+    root: TrieNode<V>,
+    len: usize,
+}
+
+impl<V> Default for TrieMap<V> {
+    fn default() -> Self {
+        TrieMap::new()
+    }
+}
+
+impl<V> TrieMap<V> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        TrieMap {
+            root: TrieNode::new(),
+            len: 0,
+        }
+    }
+
+    /// Inserts a value for `key`, returning the value it replaced, if any.
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        let mut node = &mut self.root;
+        for c in key.chars() {
+            node = node.children.entry(c).or_insert_with(TrieNode::new);
+        }
+        let old = node.value.replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Returns a reference to the value stored for `key`.
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.find(key)?.value.as_ref()
+    }
+
+    /// Returns a mutable reference to the value stored for `key`.
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        self.find_mut(key)?.value.as_mut()
+    }
+
+    /// Removes `key` from the map and returns its value. Branches left without any values are
+    /// pruned.
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        // Depth of the node whose child edge should be cut, if the removal leaves a branch
+        // without any values.
+        let cut = {
+            let mut path = LinkedListStack::new();
+            let mut node = &self.root;
+            for c in key.chars() {
+                path.push(node);
+                node = node.children.get(&c)?;
+            }
+            node.value.as_ref()?;
+
+            let mut cut = None;
+            if node.children.is_empty() {
+                while let Some(parent) = path.pop() {
+                    cut = Some(path.len());
+                    if path.is_empty() || parent.value.is_some() || parent.children.len() > 1 {
+                        break;
+                    }
+                }
+            }
+            cut
+        };
+
+        let removed = match cut {
+            None => self.find_mut(key).and_then(|node| node.value.take()),
+            Some(depth) => {
+                let mut chars = key.chars();
+                let mut node = &mut self.root;
+                for c in chars.by_ref().take(depth) {
+                    node = node.children.get_mut(&c).expect("path checked above");
+                }
+                let c = chars.next().expect("path checked above");
+                let mut branch = node.children.remove(&c).expect("path checked above");
+                // The detached branch is a single chain; unlink it node by node.
+                while let Some((_, child)) = branch.children.pop_first() {
+                    branch = child;
+                }
+                branch.value.take()
+            }
+        };
+
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Returns the values of every key starting with `prefix`, in lexicographic key order.
+    pub fn values_with_prefix(&self, prefix: &str) -> Vec<&V> {
+        let mut values = Vec::new();
+        let mut stack = LinkedListStack::new();
+        if let Some(node) = self.find(prefix) {
+            stack.push(node);
+        }
+
+        while let Some(node) = stack.pop() {
+            if let Some(value) = &node.value {
+                values.push(value);
+            }
+            for child in node.children.values().rev() {
+                stack.push(child);
+            }
+        }
+        values
+    }
+
+    /// Checks if the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of keys in the map.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    fn find(&self, key: &str) -> Option<&TrieNode<V>> {
+        let mut node = &self.root;
+        for c in key.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+
+    fn find_mut(&mut self, key: &str) -> Option<&mut TrieNode<V>> {
+        let mut node = &mut self.root;
+        for c in key.chars() {
+            node = node.children.get_mut(&c)?;
+        }
+        Some(node)
+    }
+}
+
+impl<V: Clone> Clone for TrieMap<V> {
+    fn clone(&self) -> Self {
+        /// A node being copied: its remaining source children and the copies already made.
+        struct Frame<'a, V> {
+            key: char,
+            node: &'a TrieNode<V>,
+            pending: btree_map::Iter<'a, char, TrieNode<V>>,
+            children: BTreeMap<char, TrieNode<V>>,
+        }
+
+        let mut frames = Vec::new();
+        frames.push(Frame {
+            key: '\0',
+            node: &self.root,
+            pending: self.root.children.iter(),
+            children: BTreeMap::new(),
+        });
+
+        // Copy in post-order so each node is built once all of its children are.
+        loop {
+            let frame = frames.last_mut().expect("root frame is popped last");
+            if let Some((&key, child)) = frame.pending.next() {
+                frames.push(Frame {
+                    key,
+                    node: child,
+                    pending: child.children.iter(),
+                    children: BTreeMap::new(),
+                });
+                continue;
+            }
+
+            let frame = frames.pop().expect("checked above");
+            let node = TrieNode {
+                value: frame.node.value.clone(),
+                children: frame.children,
+            };
+            match frames.last_mut() {
+                Some(parent) => {
+                    parent.children.insert(frame.key, node);
+                }
+                None => {
+                    return TrieMap {
+                        root: node,
+                        len: self.len,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<V> Drop for TrieMap<V> {
+    fn drop(&mut self) {
+        let mut pending = LinkedListStack::new();
+        pending.push(core::mem::take(&mut self.root.children));
+        while let Some(children) = pending.pop() {
+            for (_, mut child) in children {
+                pending.push(core::mem::take(&mut child.children));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    fn sample() -> super::TrieMap<i32> {
+        let mut map = super::TrieMap::new();
+        map.insert("car", 1);
+        map.insert("cart", 2);
+        map.insert("care", 3);
+        map.insert("dog", 4);
+        map.insert("", 5);
+        map
+    }
+
+    #[test]
+    fn test_trie_map_insert_get() {
+        let mut map = sample();
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.get("car"), Some(&1));
+        assert_eq!(map.get("cart"), Some(&2));
+        assert_eq!(map.get(""), Some(&5));
+        assert_eq!(map.get("ca"), None);
+        assert_eq!(map.get("cars"), None);
+
+        assert_eq!(map.insert("car", 10), Some(1));
+        assert_eq!(map.get("car"), Some(&10));
+        assert_eq!(map.len(), 5);
+    }
+
+    #[test]
+    fn test_trie_map_get_mut() {
+        let mut map = sample();
+        *map.get_mut("dog").unwrap() += 40;
+        assert_eq!(map.get("dog"), Some(&44));
+        assert!(map.get_mut("do").is_none());
+    }
+
+    #[test]
+    fn test_trie_map_remove() {
+        let mut map = sample();
+        assert_eq!(map.remove("car"), Some(1));
+        assert_eq!(map.remove("car"), None);
+        assert_eq!(map.get("cart"), Some(&2));
+        assert_eq!(map.remove("dog"), Some(4));
+        assert!(!map.root.children.contains_key(&'d'));
+        assert_eq!(map.remove("ca"), None);
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn test_trie_map_remove_prunes_only_dead_branch() {
+        let mut map = sample();
+        assert_eq!(map.remove("cart"), Some(2));
+        assert_eq!(map.get("car"), Some(&1));
+        assert_eq!(map.get("care"), Some(&3));
+        let car = &map.root.children[&'c'].children[&'a'].children[&'r'];
+        assert!(!car.children.contains_key(&'t'));
+        assert!(car.children.contains_key(&'e'));
+    }
+
+    #[test]
+    fn test_trie_map_deep_key() {
+        let key = "a".repeat(200_000);
+        let mut map = super::TrieMap::new();
+        map.insert(&key, 1);
+        map.insert("ab", 2);
+
+        let copy = map.clone();
+        assert_eq!(map.remove(&key), Some(1));
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get("ab"), Some(&2));
+        assert_eq!(map.root.children[&'a'].children.len(), 1);
+
+        assert_eq!(copy.len(), 2);
+        assert_eq!(copy.get(&key), Some(&1));
+        drop(copy);
+    }
+
+    #[test]
+    fn test_trie_map_values_with_prefix() {
+        let map = sample();
+        assert_eq!(map.values_with_prefix("car"), [&1, &3, &2]);
+        assert_eq!(map.values_with_prefix("d"), [&4]);
+        assert_eq!(map.values_with_prefix(""), [&5, &1, &3, &2, &4]);
+        assert!(map.values_with_prefix("x").is_empty());
+    }
+
+    #[test]
+    fn test_trie_map_empty() {
+        let map = super::TrieMap::<i32>::new();
+        assert!(map.is_empty());
+        assert_eq!(map.get(""), None);
+        assert_eq!(map.values_with_prefix(""), Vec::<&i32>::new());
+    }
+}